@@ -1,4 +1,4 @@
-use super::{alloc_id, get_or_assign_new_id, id_to_entity, RollSafeChildren, RollSafeId, RollSafeParent};
+use super::{alloc_id, get_or_assign_new_id, id_to_entity, push_events, RollSafeChildren, RollSafeHierarchyEvent, RollSafeId, RollSafeParent};
 use bevy::ecs::{
     bundle::Bundle,
     entity::Entity,
@@ -58,7 +58,7 @@ fn remove_from_children(world: &mut World, parent: Entity, child: Entity) {
 ///
 /// Does nothing if `child` was already a child of `parent`.
 ///
-/// Sends [`HierarchyEvent`]'s.
+/// Sends [`RollSafeHierarchyEvent`]'s.
 fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
     let previous = update_parent(world, child, parent);
     if let Some(previous_parent) = previous {
@@ -67,6 +67,17 @@ fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
             return;
         }
         remove_from_children(world, previous_parent, child);
+
+        push_events(
+            world,
+            [RollSafeHierarchyEvent::ChildMoved {
+                child,
+                previous_parent,
+                new_parent: parent,
+            }],
+        );
+    } else {
+        push_events(world, [RollSafeHierarchyEvent::ChildAdded { child, parent }]);
     }
 }
 
@@ -77,8 +88,9 @@ fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
 ///
 /// Does nothing for a child if it was already a child of `parent`.
 ///
-/// Sends [`HierarchyEvent`]'s.
+/// Sends [`RollSafeHierarchyEvent`]'s.
 fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
+    let mut events: SmallVec<[RollSafeHierarchyEvent; 8]> = SmallVec::with_capacity(children.len());
     for &child in children {
         if let Some(previous) = update_parent(world, child, parent) {
             // Do nothing if the entity already has the correct parent.
@@ -87,24 +99,35 @@ fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
             }
 
             remove_from_children(world, previous, child);
+            events.push(RollSafeHierarchyEvent::ChildMoved {
+                child,
+                previous_parent: previous,
+                new_parent: parent,
+            });
+        } else {
+            events.push(RollSafeHierarchyEvent::ChildAdded { child, parent });
         }
     }
+    push_events(world, events);
 }
 
 /// Removes entities in `children` from `parent`'s [`Children`], removing the component if it ends up empty.
 /// Also removes [`Parent`] component from `children`.
 fn remove_children(parent: Entity, children: &[Entity], world: &mut World) {
     let mut children2: SmallVec<[RollSafeId; 8]> = SmallVec::new();
+    let mut events: SmallVec<[RollSafeHierarchyEvent; 8]> = SmallVec::new();
     if let Some(parent_children) = world.get::<RollSafeChildren>(parent) {
         for &child in children {
             let Some(child_id) = world.get::<RollSafeId>(child) else { continue; };
             if parent_children.contains(&child_id) {
                 children2.push(*child_id);
+                events.push(RollSafeHierarchyEvent::ChildRemoved { child, parent });
             }
         }
     } else {
         return;
     }
+    push_events(world, events);
     for &child in children {
         world.entity_mut(child).remove::<RollSafeParent>();
     }
@@ -123,12 +146,17 @@ fn remove_children(parent: Entity, children: &[Entity], world: &mut World) {
 
 /// Removes all children from `parent` by removing its [`Children`] component, as well as removing
 /// [`Parent`] component from its children.
+///
+/// Sends [`RollSafeHierarchyEvent`]'s.
 fn clear_children(parent: Entity, world: &mut World) {
     if let Some(children) = world.entity_mut(parent).take::<RollSafeChildren>() {
+        let mut events: SmallVec<[RollSafeHierarchyEvent; 8]> = SmallVec::new();
         for &child in &children.0 {
             let Some(child) = id_to_entity(world, child) else { continue; };
             world.entity_mut(child).remove::<RollSafeParent>();
+            events.push(RollSafeHierarchyEvent::ChildRemoved { child, parent });
         }
+        push_events(world, events);
     }
 }
 
@@ -173,7 +201,7 @@ pub struct PushChildren {
 impl Command for PushChildren {
     fn apply(self, world: &mut World) {
         for child in &self.children {
-            let id = alloc_id(world);
+            let id = alloc_id(world, *child);
             if let Some(mut id2) = world.get_mut(*child) {
                 *id2 = id;
             } else {
@@ -466,6 +494,13 @@ impl<'w> WorldChildBuilder<'w> {
     pub fn spawn(&mut self, bundle: impl Bundle + Send + Sync + 'static) -> EntityWorldMut<'_> {
         let entity = self.world.spawn((bundle, RollSafeParent(self.parent_id))).id();
         push_child_unchecked(self.world, self.parent, entity);
+        push_events(
+            self.world,
+            [RollSafeHierarchyEvent::ChildAdded {
+                child: entity,
+                parent: self.parent,
+            }],
+        );
         self.world.entity_mut(entity)
     }
 
@@ -474,6 +509,13 @@ impl<'w> WorldChildBuilder<'w> {
     pub fn spawn_empty(&mut self) -> EntityWorldMut<'_> {
         let entity = self.world.spawn(RollSafeParent(self.parent_id)).id();
         push_child_unchecked(self.world, self.parent, entity);
+        push_events(
+            self.world,
+            [RollSafeHierarchyEvent::ChildAdded {
+                child: entity,
+                parent: self.parent,
+            }],
+        );
         self.world.entity_mut(entity)
     }
 
@@ -563,7 +605,7 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
                 if let Some(parent_id3) = parent_id2 {
                     parent_id = parent_id3;
                 } else {
-                    parent_id = alloc_id(world);
+                    parent_id = alloc_id(world, parent);
                     world.entity_mut(parent).insert(parent_id);
                 }
             }
@@ -660,6 +702,7 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
             self.world_scope(|world| {
                 if let Some(parent) = id_to_entity(world, parent) {
                     remove_from_children(world, parent, child);
+                    push_events(world, [RollSafeHierarchyEvent::ChildRemoved { child, parent }]);
                 }
             });
         }
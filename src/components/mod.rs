@@ -6,7 +6,7 @@ pub use parent::RollSafeParent;
 
 use bevy::ecs::component::Component;
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct RollSafeId(pub(crate) usize);
 
 pub(crate) const ROLL_SAFE_ID_PLACE_HOLDER: RollSafeId = RollSafeId(usize::MAX);
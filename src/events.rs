@@ -0,0 +1,37 @@
+use bevy::ecs::{entity::Entity, event::Event};
+
+/// An [`Event`] that is fired whenever there is a change in the roll safe hierarchy.
+///
+/// Only sent when the [`RollSafeHierarchyPlugin`] is configured with events enabled.
+///
+/// Despawning with [`rollsafe_despawn_recursive`] sends [`ChildRemoved`](Self::ChildRemoved)
+/// for the despawned entity only, not for each of its descendants.
+///
+/// [`rollsafe_despawn_recursive`]: crate::RollSafeDespawnRecursiveExt::rollsafe_despawn_recursive
+/// [`RollSafeHierarchyPlugin`]: crate::RollSafeHierarchyPlugin
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum RollSafeHierarchyEvent {
+    /// Fired whenever an [`Entity`] is added as a child to a parent.
+    ChildAdded {
+        /// The child that was added
+        child: Entity,
+        /// The parent the child was added to
+        parent: Entity,
+    },
+    /// Fired whenever a child [`Entity`] is removed from its parent.
+    ChildRemoved {
+        /// The child that was removed
+        child: Entity,
+        /// The parent the child was removed from
+        parent: Entity,
+    },
+    /// Fired whenever a child [`Entity`] is moved to a new parent.
+    ChildMoved {
+        /// The child that was moved
+        child: Entity,
+        /// The parent the child was removed from
+        previous_parent: Entity,
+        /// The parent the child was added to
+        new_parent: Entity,
+    },
+}
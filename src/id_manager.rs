@@ -2,9 +2,19 @@ use bevy::{ecs::{entity::Entity, system::{Query, ResMut, Resource}}, utils::Hash
 
 use super::RollSafeId;
 
+/// Controls what happens to a [`RollSafeId`] once its entity has been despawned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IdPolicy {
+    /// Freed ids are handed out again by later allocations.
+    #[default]
+    Reuse,
+    /// Ids are never handed out twice, so a stale id can never alias a newer entity.
+    Monotonic,
+}
 
 #[derive(Resource)]
 pub struct IdManager {
+    policy: IdPolicy,
     next_id: usize,
     unused_ids: Vec<usize>,
     id_to_entity_id: HashMap<usize, Entity>,
@@ -12,15 +22,24 @@ pub struct IdManager {
 
 impl Default for IdManager {
     fn default() -> Self {
+        Self::with_policy(IdPolicy::default())
+    }
+}
+
+impl IdManager {
+    pub fn with_policy(policy: IdPolicy) -> Self {
         Self {
+            policy,
             next_id: 0,
             unused_ids: Vec::new(),
             id_to_entity_id: HashMap::new(),
         }
     }
-}
 
-impl IdManager {
+    pub fn policy(&self) -> IdPolicy {
+        self.policy
+    }
+
     pub fn alloc_id(&mut self) -> RollSafeId {
         if let Some(id) = self.unused_ids.pop() {
             return RollSafeId(id);
@@ -31,9 +50,18 @@ impl IdManager {
     }
 
     pub fn free_id(&mut self, RollSafeId(id): RollSafeId) {
+        self.id_to_entity_id.remove(&id);
+        if self.policy == IdPolicy::Monotonic {
+            return;
+        }
         self.unused_ids.push(id);
     }
 
+    // Makes a freshly assigned id resolvable before the next update_id_entity_map
+    pub(crate) fn map_entity(&mut self, RollSafeId(id): RollSafeId, entity: Entity) {
+        self.id_to_entity_id.insert(id, entity);
+    }
+
     pub fn lookup_entity(&self, id: RollSafeId) -> Option<Entity> {
         self.id_to_entity_id.get(&id.0).map(|x| x.clone())
     }
@@ -2,32 +2,127 @@ mod components;
 pub use components::{RollSafeId, RollSafeChildren, RollSafeParent};
 
 mod id_manager;
-pub use id_manager::{IdManager, update_id_entity_map};
+pub use id_manager::{IdManager, IdPolicy, update_id_entity_map};
+
+mod events;
+pub use events::RollSafeHierarchyEvent;
+
+mod validation;
+pub use validation::{validate_hierarchy, BrokenRollSafeLinks};
 
 mod child_builder;
 pub use child_builder::{BuildChildren, BuildWorldChildren};
 
-use bevy::{app::Plugin, ecs::{entity::Entity, system::{Command, EntityCommands}, world::{EntityWorldMut, World}}};
+use bevy::{app::{Plugin, PreUpdate}, ecs::{entity::Entity, event::Events, schedule::{InternedScheduleLabel, IntoSystemConfigs, ScheduleLabel}, system::{Command, EntityCommands, Resource}, world::{EntityWorldMut, World}}};
 
 use self::components::ROLL_SAFE_ID_PLACE_HOLDER;
 
-pub struct RollSafeHierarchy;
+/// Sets up the roll safe hierarchy and its optional subsystems.
+///
+/// ```
+/// # use bevy::app::App;
+/// # use bevy_rollsafe_hierarchy::{IdPolicy, RollSafeHierarchyPlugin};
+/// App::new().add_plugins(
+///     RollSafeHierarchyPlugin::default()
+///         .with_validation(true)
+///         .with_events(true)
+///         .with_id_policy(IdPolicy::Monotonic),
+/// );
+/// ```
+pub struct RollSafeHierarchyPlugin {
+    schedule: InternedScheduleLabel,
+    validation: bool,
+    events: bool,
+    id_policy: IdPolicy,
+}
+
+impl Default for RollSafeHierarchyPlugin {
+    fn default() -> Self {
+        Self {
+            schedule: PreUpdate.intern(),
+            validation: false,
+            events: false,
+            id_policy: IdPolicy::default(),
+        }
+    }
+}
+
+impl RollSafeHierarchyPlugin {
+    /// Schedule that runs [`update_id_entity_map`] (and [`validate_hierarchy`] if enabled).
+    /// Defaults to [`PreUpdate`], use your rollback schedule when rolling back.
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Warn (once per link) about inconsistent parent/children links, checked right after
+    /// [`update_id_entity_map`]. Links created by exclusive systems or `apply_deferred` that run in
+    /// between in the same schedule are reported as missing until the next run.
+    /// The links broken as of the last check are kept in [`BrokenRollSafeLinks`].
+    pub fn with_validation(mut self, validation: bool) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Send [`RollSafeHierarchyEvent`]s when the hierarchy changes.
+    pub fn with_events(mut self, events: bool) -> Self {
+        self.events = events;
+        self
+    }
 
-impl Plugin for RollSafeHierarchy {
+    /// How the [`IdManager`] treats freed ids.
+    pub fn with_id_policy(mut self, id_policy: IdPolicy) -> Self {
+        self.id_policy = id_policy;
+        self
+    }
+}
+
+impl Plugin for RollSafeHierarchyPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app
-            .insert_resource(IdManager::default());
+            .insert_resource(IdManager::with_policy(self.id_policy))
+            .insert_resource(RollSafeHierarchyConfig { events: self.events, })
+            .add_systems(self.schedule, update_id_entity_map);
+        if self.validation {
+            app.init_resource::<BrokenRollSafeLinks>();
+            app.add_systems(self.schedule, validate_hierarchy.after(update_id_entity_map));
+        }
+        if self.events {
+            app.add_event::<RollSafeHierarchyEvent>();
+        }
+    }
+}
+
+/// Settings from [`RollSafeHierarchyPlugin`] that the hierarchy commands need at apply time.
+#[derive(Resource)]
+pub(crate) struct RollSafeHierarchyConfig {
+    pub(crate) events: bool,
+}
+
+/// Sends [`RollSafeHierarchyEvent`]'s, but only if the plugin was configured with events enabled.
+pub(crate) fn push_events(world: &mut World, events: impl IntoIterator<Item = RollSafeHierarchyEvent>) {
+    let enabled = world.get_resource::<RollSafeHierarchyConfig>().is_some_and(|config| config.events);
+    if !enabled {
+        return;
+    }
+    if let Some(mut sent_events) = world.get_resource_mut::<Events<RollSafeHierarchyEvent>>() {
+        sent_events.extend(events);
     }
 }
 
+/// Resolves `id` through the [`IdManager`] map, ignoring stale entries whose entity was
+/// despawned or no longer carries `id`.
 pub(crate) fn id_to_entity(world: &World, id: RollSafeId) -> Option<Entity> {
     let Some(id_manager) = world.get_resource::<IdManager>() else { return None; };
-    return id_manager.lookup_entity(id);
+    let entity = id_manager.lookup_entity(id)?;
+    (world.get::<RollSafeId>(entity) == Some(&id)).then_some(entity)
 }
 
-pub(crate) fn alloc_id(world: &mut World) -> RollSafeId {
+pub(crate) fn alloc_id(world: &mut World, entity: Entity) -> RollSafeId {
     let Some(mut id_manager) = world.get_resource_mut::<IdManager>() else { return ROLL_SAFE_ID_PLACE_HOLDER; };
-    return id_manager.alloc_id();
+    let id = id_manager.alloc_id();
+    id_manager.map_entity(id, entity);
+    return id;
 }
 
 pub(crate) fn free_id(world: &mut World, id: RollSafeId) {
@@ -39,7 +134,7 @@ pub(crate) fn get_or_assign_new_id(world: &mut World, entity: Entity) -> RollSaf
     if let Some(id) = world.get::<RollSafeId>(entity) {
         return *id;
     }
-    let id = alloc_id(world);
+    let id = alloc_id(world, entity);
     world.entity_mut(entity).insert(id);
     return id;
 }
@@ -78,6 +173,7 @@ fn rollsafe_despawn_recursive(world: &mut World, target: Entity) {
                 if children_empty {
                     world.entity_mut(parent_entity).remove::<RollSafeChildren>();
                 }
+                push_events(world, [RollSafeHierarchyEvent::ChildRemoved { child: at, parent: parent_entity }]);
             }
         }
         if let Some(mut children) = world.get_mut::<RollSafeChildren>(at) {
@@ -115,3 +211,121 @@ impl<'w, 's, 'a> RollSafeDespawnRecursiveExt for EntityCommands<'w, 's, 'a> {
         self.commands().add(RollSafeDespawnRecursive { target, });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::{app::{App, PreUpdate}, ecs::{entity::Entity, event::Events, schedule::ScheduleLabel}};
+
+    use super::{BrokenRollSafeLinks, BuildWorldChildren, IdManager, RollSafeParent, IdPolicy, RollSafeDespawnRecursiveExt, RollSafeHierarchyEvent, RollSafeHierarchyPlugin, RollSafeId};
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Rollback;
+
+    fn id_after_reuse(id_policy: IdPolicy) -> (RollSafeId, RollSafeId) {
+        let mut app = App::new();
+        app.add_plugins(RollSafeHierarchyPlugin::default().with_id_policy(id_policy));
+        let parent = app.world.spawn_empty().id();
+        let child = app.world.spawn_empty().id();
+        app.world.entity_mut(parent).add_child(child);
+        let freed = *app.world.get::<RollSafeId>(child).unwrap();
+        app.world.entity_mut(child).rollsafe_despawn_recursive();
+        let child = app.world.spawn_empty().id();
+        app.world.entity_mut(parent).add_child(child);
+        (freed, *app.world.get::<RollSafeId>(child).unwrap())
+    }
+
+    #[test]
+    fn reuse_policy_reuses_freed_ids() {
+        let (freed, next) = id_after_reuse(IdPolicy::Reuse);
+        assert_eq!(freed, next);
+    }
+
+    #[test]
+    fn monotonic_policy_never_reuses_ids() {
+        let (freed, next) = id_after_reuse(IdPolicy::Monotonic);
+        assert_ne!(freed, next);
+    }
+
+    fn hierarchy_events(events: bool) -> (Vec<RollSafeHierarchyEvent>, [Entity; 3]) {
+        let mut app = App::new();
+        app.add_plugins(RollSafeHierarchyPlugin::default().with_events(events));
+        // Registered regardless, so only the plugin setting decides whether events are sent.
+        app.add_event::<RollSafeHierarchyEvent>();
+        let parent = app.world.spawn_empty().id();
+        let other_parent = app.world.spawn_empty().id();
+        let child = app.world.spawn_empty().id();
+        app.world.entity_mut(parent).add_child(child);
+        app.world.entity_mut(child).set_parent(other_parent);
+        app.world.entity_mut(other_parent).remove_children(&[child]);
+        let sent = app.world.resource_mut::<Events<RollSafeHierarchyEvent>>().drain().collect();
+        (sent, [parent, other_parent, child])
+    }
+
+    #[test]
+    fn events_sent_when_enabled() {
+        let (sent, [parent, other_parent, child]) = hierarchy_events(true);
+        assert_eq!(sent, vec![
+            RollSafeHierarchyEvent::ChildAdded { child, parent },
+            RollSafeHierarchyEvent::ChildMoved { child, previous_parent: parent, new_parent: other_parent },
+            RollSafeHierarchyEvent::ChildRemoved { child, parent: other_parent },
+        ]);
+    }
+
+    #[test]
+    fn no_events_sent_when_disabled() {
+        let (sent, _) = hierarchy_events(false);
+        assert!(sent.is_empty());
+    }
+
+    #[test]
+    fn replace_children_reports_dropped_children() {
+        let mut app = App::new();
+        app.add_plugins(RollSafeHierarchyPlugin::default().with_events(true));
+        let parent = app.world.spawn_empty().id();
+        let kept = app.world.spawn_empty().id();
+        let dropped = app.world.spawn_empty().id();
+        app.world.entity_mut(parent).push_children(&[kept, dropped]);
+        app.world.resource_mut::<Events<RollSafeHierarchyEvent>>().clear();
+        app.world.entity_mut(parent).replace_children(&[kept]);
+        let sent: Vec<_> = app.world.resource_mut::<Events<RollSafeHierarchyEvent>>().drain().collect();
+        assert!(sent.contains(&RollSafeHierarchyEvent::ChildRemoved { child: dropped, parent }));
+    }
+
+    #[test]
+    fn validation_tracks_broken_links_until_repaired() {
+        let mut app = App::new();
+        app.add_plugins(RollSafeHierarchyPlugin::default().with_validation(true));
+        let parent = app.world.spawn_empty().id();
+        let child = app.world.spawn_empty().id();
+        app.world.entity_mut(parent).add_child(child);
+        let parent_id = *app.world.get::<RollSafeId>(parent).unwrap();
+        app.world.run_schedule(PreUpdate);
+        assert!(app.world.resource::<BrokenRollSafeLinks>().is_empty());
+
+        app.world.despawn(parent);
+        app.world.run_schedule(PreUpdate);
+        assert!(app.world.resource::<BrokenRollSafeLinks>().parents.contains(&(child, parent_id)));
+
+        app.world.entity_mut(child).remove::<RollSafeParent>();
+        app.world.run_schedule(PreUpdate);
+        assert!(app.world.resource::<BrokenRollSafeLinks>().is_empty());
+
+        // Breaking the same link again must be reported again.
+        app.world.entity_mut(child).insert(RollSafeParent(parent_id));
+        app.world.run_schedule(PreUpdate);
+        assert!(app.world.resource::<BrokenRollSafeLinks>().parents.contains(&(child, parent_id)));
+    }
+
+    #[test]
+    fn id_map_updated_in_configured_schedule() {
+        let mut app = App::new();
+        app.add_plugins(RollSafeHierarchyPlugin::default().with_schedule(Rollback));
+        // Inserted directly (e.g. restored by a rollback), so only update_id_entity_map can map it.
+        let id = RollSafeId(7);
+        let entity = app.world.spawn(id).id();
+        let _ = app.world.try_run_schedule(PreUpdate);
+        assert_eq!(app.world.resource::<IdManager>().lookup_entity(id), None);
+        app.world.run_schedule(Rollback);
+        assert_eq!(app.world.resource::<IdManager>().lookup_entity(id), Some(entity));
+    }
+}
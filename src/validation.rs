@@ -0,0 +1,85 @@
+use bevy::{ecs::{entity::Entity, system::{Query, Res, ResMut, Resource}}, log::warn, utils::HashSet};
+
+use super::{IdManager, RollSafeChildren, RollSafeId, RollSafeParent};
+
+/// Links found broken by the last run of [`validate_hierarchy`].
+///
+/// Only links that are not already in here get warned about, so a persistent inconsistency is
+/// reported once rather than every run.
+#[derive(Resource, Default)]
+pub struct BrokenRollSafeLinks {
+    /// `(child, parent id)` for entities whose parent is missing or does not list them.
+    pub(crate) parents: HashSet<(Entity, RollSafeId)>,
+    /// `(parent, child id)` for entities listing a child that is missing or does not point back.
+    pub(crate) children: HashSet<(Entity, RollSafeId)>,
+}
+
+impl BrokenRollSafeLinks {
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty() && self.children.is_empty()
+    }
+}
+
+// Run straight after update_id_entity_map, so lookups reflect the current frame.
+// Links created by systems that apply their commands in between are reported as missing.
+pub fn validate_hierarchy(
+    nodes: Query<(Entity, &RollSafeId, Option<&RollSafeParent>, Option<&RollSafeChildren>)>,
+    id_manager: Res<IdManager>,
+    mut broken: ResMut<BrokenRollSafeLinks>,
+) {
+    let mut parents = HashSet::new();
+    let mut children_links = HashSet::new();
+    for (entity, id, parent, children) in &nodes {
+        if let Some(parent) = parent {
+            let link = (entity, parent.get());
+            match id_manager.lookup_entity(parent.get()) {
+                Some(parent_entity) => {
+                    let listed = nodes
+                        .get(parent_entity)
+                        .ok()
+                        .and_then(|(_, _, _, parent_children)| parent_children)
+                        .is_some_and(|parent_children| parent_children.contains(id));
+                    if !listed {
+                        if !broken.parents.contains(&link) {
+                            warn!("{:?} has parent {:?} but is missing from its children", entity, parent_entity);
+                        }
+                        parents.insert(link);
+                    }
+                }
+                None => {
+                    if !broken.parents.contains(&link) {
+                        warn!("{:?} has a parent {:?} that does not exist", entity, parent.get());
+                    }
+                    parents.insert(link);
+                }
+            }
+        }
+        let Some(children) = children else { continue; };
+        for child in children {
+            let link = (entity, *child);
+            match id_manager.lookup_entity(*child) {
+                Some(child_entity) => {
+                    let parented = nodes
+                        .get(child_entity)
+                        .ok()
+                        .and_then(|(_, _, child_parent, _)| child_parent)
+                        .is_some_and(|child_parent| child_parent.get() == *id);
+                    if !parented {
+                        if !broken.children.contains(&link) {
+                            warn!("{:?} lists child {:?} which does not point back to it", entity, child_entity);
+                        }
+                        children_links.insert(link);
+                    }
+                }
+                None => {
+                    if !broken.children.contains(&link) {
+                        warn!("{:?} has a child {:?} that does not exist", entity, child);
+                    }
+                    children_links.insert(link);
+                }
+            }
+        }
+    }
+    broken.parents = parents;
+    broken.children = children_links;
+}